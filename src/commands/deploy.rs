@@ -0,0 +1,138 @@
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+
+use crate::config::{NetworkConfig, ProjectConfig};
+use crate::hash::code_hash;
+use crate::output::{DeployReport, Format};
+use swiftsc_frontend::{analyze, parse};
+
+/// Submits the deployment transaction to `network` and returns the resulting
+/// contract address. This is the only part of the pipeline that actually
+/// talks to a chain; everything upstream (build, hashing) is deterministic.
+fn broadcast_deploy(network: &NetworkConfig, wasm_bytes: &[u8]) -> Result<String> {
+    let signer = network
+        .signer
+        .as_deref()
+        .context("network has no `signer` configured in SwiftSC-Lang.toml")?;
+
+    let client = swiftsc_rpc::Client::connect(&network.rpc, &network.chain_id)
+        .with_context(|| format!("could not connect to RPC endpoint `{}`", network.rpc))?;
+
+    let address = client
+        .deploy_contract(wasm_bytes, signer)
+        .context("deployment transaction failed")?;
+
+    Ok(address)
+}
+
+/// Builds `path` to WASM, resolves `network` against `SwiftSC-Lang.toml`,
+/// and deploys the contract. With `dry_run`, stops after computing the code
+/// hash without broadcasting anything. Returns `Ok(false)` for a compile
+/// error instead of bailing, so `--format json` still gets a `DeployReport`
+/// on stdout for the failures callers most need to see structured.
+pub fn run(
+    path: &Path,
+    network: &str,
+    root: Option<PathBuf>,
+    dry_run: bool,
+    format: Format,
+) -> Result<bool> {
+    let config = ProjectConfig::load(path, root.as_ref())
+        .context("could not load SwiftSC-Lang.toml for this contract")?;
+
+    let network_config = config.networks.get(network).with_context(|| {
+        format!(
+            "network `{network}` is not configured in SwiftSC-Lang.toml (add a [networks.{network}] table)"
+        )
+    })?;
+
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("could not read file `{}`", path.display()))?;
+
+    if !format.is_json() {
+        println!("--- Building: {} ---", path.display());
+    }
+
+    let options = swiftsc_backend::CompileOptions {
+        target: config.build.target.clone(),
+        gas_metering: config.build.gas_metering,
+    };
+
+    let wasm_bytes = match parse(&content) {
+        Ok(ast) => match analyze(&ast, root) {
+            Ok(_) => match swiftsc_backend::compile_with_options(&ast, &options) {
+                Ok(bytes) => bytes,
+                Err(e) => return Ok(report_failure(format, network, dry_run, format!("Codegen Error: {e}"))),
+            },
+            Err(e) => return Ok(report_failure(format, network, dry_run, format!("Semantic Error: {e}"))),
+        },
+        Err(e) => return Ok(report_failure(format, network, dry_run, format!("Parse Error: {e}"))),
+    };
+
+    let hash = code_hash(&wasm_bytes)?;
+
+    if dry_run {
+        let report = DeployReport {
+            success: true,
+            dry_run: true,
+            network: network.to_string(),
+            address: None,
+            code_hash: Some(hash.clone()),
+            error: None,
+        };
+        if format.is_json() {
+            crate::output::emit_json(&report);
+        } else {
+            println!("--- Dry run: {network} ---");
+            println!("Code hash: 0x{hash}");
+            println!("(stopped before broadcasting; no transaction submitted)");
+        }
+        return Ok(true);
+    }
+
+    if !format.is_json() {
+        println!("--- Deploying to: {network} ({}) ---", network_config.rpc);
+    }
+    let address = broadcast_deploy(network_config, &wasm_bytes)?;
+
+    let report = DeployReport {
+        success: true,
+        dry_run: false,
+        network: network.to_string(),
+        address: Some(address),
+        code_hash: Some(hash),
+        error: None,
+    };
+
+    if format.is_json() {
+        crate::output::emit_json(&report);
+    } else {
+        println!("✓ Deployed contract: {}", report.address.as_deref().unwrap_or_default());
+        println!("  code hash: 0x{}", report.code_hash.as_deref().unwrap_or_default());
+    }
+
+    Ok(true)
+}
+
+/// Emits a `DeployReport{success: false, error: Some(..)}` for a compile-pipeline
+/// failure (parse/semantic/codegen): JSON on stdout in `--format json`, the bare
+/// message on stderr otherwise. Returns `false` for the caller to turn into a
+/// non-zero exit code.
+fn report_failure(format: Format, network: &str, dry_run: bool, error: String) -> bool {
+    let report = DeployReport {
+        success: false,
+        dry_run,
+        network: network.to_string(),
+        address: None,
+        code_hash: None,
+        error: Some(error),
+    };
+
+    if format.is_json() {
+        crate::output::emit_json(&report);
+    } else {
+        eprintln!("{}", report.error.as_deref().unwrap_or("Deploy failed"));
+    }
+
+    false
+}
@@ -0,0 +1,4 @@
+pub mod deploy;
+pub mod schema;
+pub mod test;
+pub mod verify;
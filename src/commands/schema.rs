@@ -0,0 +1,206 @@
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+use swiftsc_frontend::ast::{Contract, Event, Function, StorageField, TypeExpr};
+use swiftsc_frontend::{analyze, parse};
+
+use crate::output::Format;
+
+/// Schema format version. Bump whenever the shape of [`ContractSchema`]
+/// changes in a way that could break off-chain consumers.
+const SCHEMA_VERSION: u32 = 1;
+
+#[derive(Serialize)]
+struct ContractSchema {
+    swiftsc_schema_version: u32,
+    contract: String,
+    storage: Vec<FieldSchema>,
+    functions: Vec<FunctionSchema>,
+    events: Vec<EventSchema>,
+}
+
+#[derive(Serialize)]
+struct FieldSchema {
+    name: String,
+    #[serde(rename = "type")]
+    ty: String,
+}
+
+#[derive(Serialize)]
+struct FunctionSchema {
+    name: String,
+    params: Vec<FieldSchema>,
+    #[serde(rename = "returns", skip_serializing_if = "Option::is_none")]
+    return_type: Option<String>,
+}
+
+#[derive(Serialize)]
+struct EventSchema {
+    name: String,
+    fields: Vec<FieldSchema>,
+}
+
+/// Emitted in `--format json` in place of [`ContractSchema`] when a
+/// parse/semantic error prevents a schema from being generated at all.
+#[derive(Serialize)]
+struct SchemaFailure {
+    success: bool,
+    error: String,
+}
+
+/// Renders a resolved AST type into the stable string form used throughout
+/// the schema (e.g. `HashMap<Address,u64>`, `Result<()>`, `u64`).
+fn render_type(ty: &TypeExpr) -> String {
+    match ty {
+        TypeExpr::Named(name) => name.clone(),
+        TypeExpr::Generic(name, args) => {
+            let rendered_args: Vec<String> = args.iter().map(render_type).collect();
+            format!("{name}<{}>", rendered_args.join(","))
+        }
+        TypeExpr::Tuple(elems) => {
+            let rendered: Vec<String> = elems.iter().map(render_type).collect();
+            format!("({})", rendered.join(","))
+        }
+        TypeExpr::Unit => "()".to_string(),
+    }
+}
+
+fn storage_schema(field: &StorageField) -> FieldSchema {
+    FieldSchema {
+        name: field.name.clone(),
+        ty: render_type(&field.ty),
+    }
+}
+
+fn function_schema(func: &Function) -> FunctionSchema {
+    FunctionSchema {
+        name: func.name.clone(),
+        params: func
+            .params
+            .iter()
+            .map(|p| FieldSchema {
+                name: p.name.clone(),
+                ty: render_type(&p.ty),
+            })
+            .collect(),
+        return_type: func.return_type.as_ref().map(render_type),
+    }
+}
+
+fn event_schema(event: &Event) -> EventSchema {
+    EventSchema {
+        name: event.name.clone(),
+        fields: event.fields.iter().map(storage_schema).collect(),
+    }
+}
+
+fn contract_schema(contract: &Contract) -> ContractSchema {
+    ContractSchema {
+        swiftsc_schema_version: SCHEMA_VERSION,
+        contract: contract.name.clone(),
+        storage: contract.storage.iter().map(storage_schema).collect(),
+        functions: contract
+            .functions
+            .iter()
+            .filter(|f| f.is_public)
+            .map(function_schema)
+            .collect(),
+        events: contract.events.iter().map(event_schema).collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::render_type;
+    use swiftsc_frontend::ast::TypeExpr;
+
+    #[test]
+    fn named() {
+        assert_eq!(render_type(&TypeExpr::Named("u64".to_string())), "u64");
+    }
+
+    #[test]
+    fn unit() {
+        assert_eq!(render_type(&TypeExpr::Unit), "()");
+    }
+
+    #[test]
+    fn generic() {
+        let ty = TypeExpr::Generic(
+            "HashMap".to_string(),
+            vec![
+                TypeExpr::Named("Address".to_string()),
+                TypeExpr::Named("u64".to_string()),
+            ],
+        );
+        assert_eq!(render_type(&ty), "HashMap<Address,u64>");
+    }
+
+    #[test]
+    fn tuple() {
+        let ty = TypeExpr::Tuple(vec![TypeExpr::Named("u64".to_string()), TypeExpr::Unit]);
+        assert_eq!(render_type(&ty), "(u64,())");
+    }
+
+    #[test]
+    fn nested_generic() {
+        let ty = TypeExpr::Generic(
+            "Result".to_string(),
+            vec![TypeExpr::Generic(
+                "Vec".to_string(),
+                vec![TypeExpr::Named("u64".to_string())],
+            )],
+        );
+        assert_eq!(render_type(&ty), "Result<Vec<u64>>");
+    }
+}
+
+/// Parses and analyzes `path`, then emits a versioned JSON schema describing
+/// its public interface (storage layout, public functions, events) for
+/// consumption by off-chain tooling. Mirrors the CosmWasm `schema` pattern.
+/// The schema is always written to `output_path` on success; in
+/// `--format json`, a [`ContractSchema`] or (on a parse/semantic failure) a
+/// [`SchemaFailure`] is also printed to stdout, same as `Build`/`Deploy`/
+/// `Verify`/`Test` emit a report instead of only a human summary line.
+/// Returns `Ok(false)` on a parse/semantic failure instead of bailing.
+pub fn run(path: &Path, output: Option<PathBuf>, root: Option<PathBuf>, format: Format) -> Result<bool> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("could not read file `{}`", path.display()))?;
+
+    let schema = match parse(&content) {
+        Ok(ast) => match analyze(&ast, root) {
+            Ok(_) => contract_schema(&ast.contract),
+            Err(e) => return Ok(report_failure(format, format!("Semantic Error: {e}"))),
+        },
+        Err(e) => return Ok(report_failure(format, format!("Parse Error: {e}"))),
+    };
+
+    let json = serde_json::to_string_pretty(&schema).context("could not serialize schema")?;
+
+    let output_path = output.unwrap_or_else(|| path.with_extension("schema.json"));
+    std::fs::write(&output_path, &json)
+        .with_context(|| format!("could not write output file `{}`", output_path.display()))?;
+
+    if format.is_json() {
+        crate::output::emit_json(&schema);
+    } else {
+        println!("Schema Generated: {}", output_path.display());
+    }
+    Ok(true)
+}
+
+/// Emits a `SchemaFailure{success: false, error: ..}` for a parse/semantic
+/// error: JSON on stdout in `--format json`, the bare message on stderr
+/// otherwise. Returns `false` for the caller to turn into a non-zero exit code.
+fn report_failure(format: Format, error: String) -> bool {
+    if format.is_json() {
+        crate::output::emit_json(&SchemaFailure {
+            success: false,
+            error,
+        });
+    } else {
+        eprintln!("{error}");
+    }
+    false
+}
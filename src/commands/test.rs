@@ -0,0 +1,454 @@
+use anyhow::{bail, Context, Result};
+use std::collections::HashMap;
+use std::path::Path;
+use wasmtime::{Caller, Engine, Instance, Linker, Module, Store};
+
+use swiftsc_frontend::{analyze, parse};
+
+use crate::config::ProjectConfig;
+use crate::output::{self, Format};
+
+/// Host-visible state for a single test invocation.
+///
+/// A fresh `HostState` is created per test case so that storage, the
+/// simulated caller address, and remaining gas never leak between tests.
+struct HostState {
+    storage: HashMap<Vec<u8>, Vec<u8>>,
+    caller: [u8; 32],
+    gas_remaining: u64,
+}
+
+impl HostState {
+    fn new() -> Self {
+        Self {
+            storage: HashMap::new(),
+            caller: [0x11; 32],
+            gas_remaining: 10_000_000,
+        }
+    }
+}
+
+/// Outcome of running a single `test_*` export.
+struct TestResult {
+    name: String,
+    passed: bool,
+    message: Option<String>,
+}
+
+/// Reads `len` bytes out of the instance's exported linear memory at `ptr`.
+fn read_memory(caller: &mut Caller<'_, HostState>, ptr: i32, len: i32) -> Result<Vec<u8>> {
+    let memory = caller
+        .get_export("memory")
+        .and_then(|e| e.into_memory())
+        .context("module does not export linear memory as `memory`")?;
+    let start = ptr as usize;
+    let end = start
+        .checked_add(len as usize)
+        .context("pointer + length overflowed")?;
+    memory
+        .data(&mut *caller)
+        .get(start..end)
+        .map(|bytes| bytes.to_vec())
+        .with_context(|| format!("memory access [{start}..{end}) out of bounds"))
+}
+
+/// Writes `bytes` into the instance's exported linear memory at `ptr`.
+fn write_memory(caller: &mut Caller<'_, HostState>, ptr: i32, bytes: &[u8]) -> Result<()> {
+    let memory = caller
+        .get_export("memory")
+        .and_then(|e| e.into_memory())
+        .context("module does not export linear memory as `memory`")?;
+    memory
+        .write(&mut *caller, ptr as usize, bytes)
+        .context("memory write out of bounds")
+}
+
+/// Builds the host module (`caller`, storage get/set, gas accounting) that
+/// compiled contracts import, mirroring the runtime the chain would provide.
+/// The `caller` and storage functions read their arguments out of (and write
+/// results into) the instance's own linear memory, so storage keys/values and the caller
+/// address round-trip as real bytes instead of raw pointer values.
+fn build_linker(engine: &Engine) -> Result<Linker<HostState>> {
+    let mut linker = Linker::new(engine);
+
+    linker.func_wrap(
+        "env",
+        "caller",
+        |mut caller: Caller<'_, HostState>, out_ptr: i32| -> Result<()> {
+            let addr = caller.data().caller;
+            write_memory(&mut caller, out_ptr, &addr)
+        },
+    )?;
+
+    linker.func_wrap(
+        "env",
+        "storage_read",
+        |mut caller: Caller<'_, HostState>,
+         key_ptr: i32,
+         key_len: i32,
+         out_ptr: i32,
+         out_max_len: i32|
+         -> Result<i32> {
+            let key = read_memory(&mut caller, key_ptr, key_len)?;
+            let value = caller.data().storage.get(&key).cloned();
+            match value {
+                None => Ok(-1),
+                Some(bytes) => {
+                    if bytes.len() as i32 > out_max_len {
+                        bail!(
+                            "storage value ({} bytes) does not fit in output buffer ({out_max_len} bytes)",
+                            bytes.len()
+                        );
+                    }
+                    write_memory(&mut caller, out_ptr, &bytes)?;
+                    Ok(bytes.len() as i32)
+                }
+            }
+        },
+    )?;
+
+    linker.func_wrap(
+        "env",
+        "storage_write",
+        |mut caller: Caller<'_, HostState>, key_ptr: i32, key_len: i32, val_ptr: i32, val_len: i32| -> Result<()> {
+            let key = read_memory(&mut caller, key_ptr, key_len)?;
+            let value = read_memory(&mut caller, val_ptr, val_len)?;
+            caller.data_mut().storage.insert(key, value);
+            Ok(())
+        },
+    )?;
+
+    linker.func_wrap(
+        "env",
+        "consume_gas",
+        |mut caller: Caller<'_, HostState>, amount: i64| {
+            let state = caller.data_mut();
+            state.gas_remaining = state.gas_remaining.saturating_sub(amount as u64);
+        },
+    )?;
+
+    Ok(linker)
+}
+
+/// Instantiates `wasm_bytes` with a fresh [`HostState`] and invokes every
+/// exported function whose name starts with `test_`. A trap, an exported
+/// error return, or an instantiation failure counts as a failed test.
+fn run_tests_in_module(engine: &Engine, linker: &Linker<HostState>, wasm_bytes: &[u8]) -> Result<Vec<TestResult>> {
+    let module = Module::new(engine, wasm_bytes).context("failed to load compiled module")?;
+
+    let test_names: Vec<String> = module
+        .exports()
+        .filter_map(|e| {
+            let name = e.name();
+            if name.starts_with("test_") {
+                Some(name.to_string())
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    let mut results = Vec::new();
+    for name in test_names {
+        let mut store = Store::new(engine, HostState::new());
+        let instance = match linker.instantiate(&mut store, &module) {
+            Ok(i) => i,
+            Err(e) => {
+                results.push(TestResult {
+                    name,
+                    passed: false,
+                    message: Some(format!("instantiation failed: {e}")),
+                });
+                continue;
+            }
+        };
+
+        results.push(run_one_test(&mut store, &instance, &name));
+    }
+
+    Ok(results)
+}
+
+fn run_one_test(store: &mut Store<HostState>, instance: &Instance, name: &str) -> TestResult {
+    let func = match instance.get_typed_func::<(), i32>(&mut *store, name) {
+        Ok(f) => f,
+        Err(e) => {
+            return TestResult {
+                name: name.to_string(),
+                passed: false,
+                message: Some(format!("`{name}` is not a callable test export: {e}")),
+            };
+        }
+    };
+
+    match func.call(&mut *store, ()) {
+        Ok(0) => TestResult {
+            name: name.to_string(),
+            passed: true,
+            message: None,
+        },
+        Ok(code) => TestResult {
+            name: name.to_string(),
+            passed: false,
+            message: Some(format!("returned non-zero status {code}")),
+        },
+        Err(trap) => TestResult {
+            name: name.to_string(),
+            passed: false,
+            message: Some(format!("trapped: {trap}")),
+        },
+    }
+}
+
+/// Discovers every `*.stc` file under `<path>/tests`, compiles it to WASM,
+/// and runs its `test_*` exports. Returns `Ok(true)` when every test passed.
+/// In `--format json`, human progress lines are suppressed and a single
+/// [`TestReport`] is emitted on stdout instead, same as `Build`/`Deploy`/`Verify`.
+pub fn run(path: &Path, format: Format) -> Result<bool> {
+    let human = !format.is_json();
+
+    if human {
+        println!("--- Running tests in: {} ---", path.display());
+    }
+
+    let root = path.to_path_buf();
+    let config = ProjectConfig::load(path, Some(&root))
+        .context("could not load SwiftSC-Lang.toml for this contract")?;
+    let options = swiftsc_backend::CompileOptions {
+        target: config.build.target.clone(),
+        gas_metering: config.build.gas_metering,
+    };
+
+    let test_dir = path.join("tests");
+    if !test_dir.exists() {
+        return Ok(report_early_failure(format, "No tests directory found"));
+    }
+
+    let mut test_files = Vec::new();
+    for entry in std::fs::read_dir(&test_dir)
+        .with_context(|| format!("could not read directory `{}`", test_dir.display()))?
+    {
+        let entry = entry?;
+        let entry_path = entry.path();
+        if entry_path.extension().and_then(|e| e.to_str()) == Some("stc") {
+            test_files.push(entry_path);
+        }
+    }
+
+    if test_files.is_empty() {
+        return Ok(report_early_failure(
+            format,
+            &format!("No *.stc test files found under {}", test_dir.display()),
+        ));
+    }
+
+    let engine = Engine::default();
+    let linker = build_linker(&engine)?;
+
+    let mut total_passed = 0;
+    let mut total_failed = 0;
+    let mut json_results = Vec::new();
+    let mut empty_files = Vec::new();
+
+    for file in &test_files {
+        let content = std::fs::read_to_string(file)
+            .with_context(|| format!("could not read file `{}`", file.display()))?;
+        let file_name = file.display().to_string();
+
+        if human {
+            println!("--- {} ---", file.display());
+        }
+
+        let ast = match parse(&content) {
+            Ok(ast) => ast,
+            Err(e) => {
+                record_file_failure(
+                    human,
+                    &mut json_results,
+                    &mut total_failed,
+                    &file_name,
+                    format!("parse error: {e}"),
+                );
+                continue;
+            }
+        };
+
+        if let Err(e) = analyze(&ast, None) {
+            record_file_failure(
+                human,
+                &mut json_results,
+                &mut total_failed,
+                &file_name,
+                format!("semantic error: {e}"),
+            );
+            continue;
+        }
+
+        let wasm_bytes = match swiftsc_backend::compile_with_options(&ast, &options) {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                record_file_failure(
+                    human,
+                    &mut json_results,
+                    &mut total_failed,
+                    &file_name,
+                    format!("codegen error: {e}"),
+                );
+                continue;
+            }
+        };
+
+        let results = run_tests_in_module(&engine, &linker, &wasm_bytes)?;
+        if results.is_empty() {
+            if human {
+                println!("  (no test_* exports found)");
+            }
+            empty_files.push(file_name.clone());
+            continue;
+        }
+
+        for result in &results {
+            if result.passed {
+                if human {
+                    println!("  ✓ {}", result.name);
+                }
+                total_passed += 1;
+            } else {
+                if human {
+                    println!(
+                        "  ✗ {} - {}",
+                        result.name,
+                        result.message.as_deref().unwrap_or("failed")
+                    );
+                }
+                total_failed += 1;
+            }
+            json_results.push(output::JsonTestResult {
+                file: file_name.clone(),
+                name: result.name.clone(),
+                passed: result.passed,
+                message: result.message.clone(),
+            });
+        }
+    }
+
+    if human {
+        println!("\n{total_passed} passed, {total_failed} failed");
+    } else {
+        output::emit_json(&output::TestReport {
+            success: total_failed == 0,
+            passed: total_passed,
+            failed: total_failed,
+            results: json_results,
+            empty_files,
+            error: None,
+        });
+    }
+
+    Ok(total_failed == 0)
+}
+
+/// Records a whole-file compile failure (parse/semantic/codegen) as a single
+/// failing result named `<compile>`, since it prevented every test in the
+/// file from running at all.
+fn record_file_failure(
+    human: bool,
+    json_results: &mut Vec<output::JsonTestResult>,
+    total_failed: &mut usize,
+    file_name: &str,
+    message: String,
+) {
+    if human {
+        eprintln!("  ✗ {message}");
+    }
+    json_results.push(output::JsonTestResult {
+        file: file_name.to_string(),
+        name: "<compile>".to_string(),
+        passed: false,
+        message: Some(message),
+    });
+    *total_failed += 1;
+}
+
+/// Reports a failure that aborts before any test file is even discovered
+/// (no `tests/` directory, no `*.stc` files in it). Always returns `false`.
+fn report_early_failure(format: Format, message: &str) -> bool {
+    if format.is_json() {
+        output::emit_json(&output::TestReport {
+            success: false,
+            passed: 0,
+            failed: 0,
+            results: Vec::new(),
+            empty_files: Vec::new(),
+            error: Some(message.to_string()),
+        });
+    } else {
+        eprintln!("✗ {message}");
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A hand-built module that exercises `storage_write`/`storage_read`
+    /// through the real host bindings: a round trip through present memory,
+    /// a miss on a key that was never written, and a value that doesn't fit
+    /// the caller's output buffer.
+    const STORAGE_TEST_MODULE: &str = r#"
+        (module
+          (import "env" "storage_write" (func $storage_write (param i32 i32 i32 i32)))
+          (import "env" "storage_read" (func $storage_read (param i32 i32 i32 i32) (result i32)))
+          (memory (export "memory") 1)
+          (data (i32.const 0) "key1")
+          (data (i32.const 16) "value123")
+
+          (func (export "test_storage_roundtrip") (result i32)
+            (local $len i32)
+            (call $storage_write (i32.const 0) (i32.const 4) (i32.const 16) (i32.const 8))
+            (local.set $len
+              (call $storage_read (i32.const 0) (i32.const 4) (i32.const 64) (i32.const 32)))
+            (if (i32.ne (local.get $len) (i32.const 8))
+              (then (return (i32.const 1))))
+            (if (i32.ne (i32.load (i32.const 64)) (i32.load (i32.const 16)))
+              (then (return (i32.const 1))))
+            (i32.const 0))
+
+          (func (export "test_storage_miss") (result i32)
+            (if (i32.ne
+                  (call $storage_read (i32.const 0) (i32.const 4) (i32.const 64) (i32.const 32))
+                  (i32.const -1))
+              (then (return (i32.const 1))))
+            (i32.const 0))
+
+          (func (export "test_storage_overflow_traps") (result i32)
+            (call $storage_write (i32.const 0) (i32.const 4) (i32.const 16) (i32.const 8))
+            (call $storage_read (i32.const 0) (i32.const 4) (i32.const 64) (i32.const 2))))
+    "#;
+
+    fn find<'a>(results: &'a [TestResult], name: &str) -> &'a TestResult {
+        results
+            .iter()
+            .find(|r| r.name == name)
+            .unwrap_or_else(|| panic!("no result for `{name}`"))
+    }
+
+    #[test]
+    fn storage_round_trip_and_bounds() {
+        let engine = Engine::default();
+        let linker = build_linker(&engine).unwrap();
+        let results =
+            run_tests_in_module(&engine, &linker, STORAGE_TEST_MODULE.as_bytes()).unwrap();
+
+        let roundtrip = find(&results, "test_storage_roundtrip");
+        assert!(roundtrip.passed, "{:?}", roundtrip.message);
+
+        let miss = find(&results, "test_storage_miss");
+        assert!(miss.passed, "{:?}", miss.message);
+
+        let overflow = find(&results, "test_storage_overflow_traps");
+        assert!(!overflow.passed);
+        assert!(overflow.message.as_deref().unwrap_or("").contains("trapped"));
+    }
+}
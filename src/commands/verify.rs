@@ -0,0 +1,98 @@
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+
+use crate::config::ProjectConfig;
+use crate::hash::{code_hash, first_differing_section};
+use crate::output::{Format, VerifyReport};
+use swiftsc_frontend::{analyze, parse};
+
+/// Recompiles `path` with the project's pinned build settings, hashes the
+/// result, and compares it against the hash of `wasm`. Returns `Ok(true)`
+/// when the hashes match.
+pub fn run(path: &Path, wasm: &Path, root: Option<PathBuf>, format: Format) -> Result<bool> {
+    let config = ProjectConfig::load(path, root.as_ref())
+        .context("could not load SwiftSC-Lang.toml for this contract")?;
+
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("could not read file `{}`", path.display()))?;
+
+    let options = swiftsc_backend::CompileOptions {
+        target: config.build.target.clone(),
+        gas_metering: config.build.gas_metering,
+    };
+
+    let report = match parse(&content) {
+        Ok(ast) => match analyze(&ast, root) {
+            Ok(_) => match swiftsc_backend::compile_with_options(&ast, &options) {
+                Ok(rebuilt) => {
+                    let rebuilt_hash = code_hash(&rebuilt)?;
+                    let supplied = std::fs::read(wasm)
+                        .with_context(|| format!("could not read file `{}`", wasm.display()))?;
+                    let supplied_hash = code_hash(&supplied)?;
+                    let success = rebuilt_hash == supplied_hash;
+
+                    if !format.is_json() {
+                        println!("Rebuilt hash:  0x{rebuilt_hash}");
+                        println!("Supplied hash: 0x{supplied_hash}");
+                        if success {
+                            println!("✓ Verified: `{}` matches `{}`", wasm.display(), path.display());
+                        } else {
+                            println!(
+                                "✗ Mismatch: `{}` does not correspond to `{}`",
+                                wasm.display(),
+                                path.display()
+                            );
+                            match first_differing_section(&rebuilt, &supplied)? {
+                                Some(section) => println!("  (first differing section: {section})"),
+                                None => println!(
+                                    "  (sections match byte-for-byte; only non-canonicalized metadata differs)"
+                                ),
+                            }
+                        }
+                    }
+
+                    VerifyReport {
+                        success,
+                        path: path.display().to_string(),
+                        wasm: wasm.display().to_string(),
+                        rebuilt_hash: Some(rebuilt_hash),
+                        supplied_hash: Some(supplied_hash),
+                        error: None,
+                    }
+                }
+                Err(e) => VerifyReport {
+                    success: false,
+                    path: path.display().to_string(),
+                    wasm: wasm.display().to_string(),
+                    rebuilt_hash: None,
+                    supplied_hash: None,
+                    error: Some(format!("Codegen Error: {e}")),
+                },
+            },
+            Err(e) => VerifyReport {
+                success: false,
+                path: path.display().to_string(),
+                wasm: wasm.display().to_string(),
+                rebuilt_hash: None,
+                supplied_hash: None,
+                error: Some(format!("Semantic Error: {e}")),
+            },
+        },
+        Err(e) => VerifyReport {
+            success: false,
+            path: path.display().to_string(),
+            wasm: wasm.display().to_string(),
+            rebuilt_hash: None,
+            supplied_hash: None,
+            error: Some(format!("Parse Error: {e}")),
+        },
+    };
+
+    if format.is_json() {
+        crate::output::emit_json(&report);
+    } else if let Some(error) = &report.error {
+        eprintln!("{error}");
+    }
+
+    Ok(report.success)
+}
@@ -0,0 +1,67 @@
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Parsed `SwiftSC-Lang.toml`, the per-project manifest written by `Init`.
+#[derive(Debug, Deserialize)]
+pub struct ProjectConfig {
+    pub package: PackageConfig,
+    #[serde(default)]
+    pub build: BuildConfig,
+    #[serde(default)]
+    pub networks: HashMap<String, NetworkConfig>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PackageConfig {
+    pub name: String,
+    pub version: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BuildConfig {
+    #[serde(default = "default_target")]
+    pub target: String,
+    #[serde(default)]
+    pub gas_metering: bool,
+}
+
+impl Default for BuildConfig {
+    fn default() -> Self {
+        Self {
+            target: default_target(),
+            gas_metering: false,
+        }
+    }
+}
+
+fn default_target() -> String {
+    "wasm32-unknown-unknown".to_string()
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NetworkConfig {
+    pub rpc: String,
+    pub chain_id: String,
+    pub signer: Option<String>,
+}
+
+impl ProjectConfig {
+    /// Loads `SwiftSC-Lang.toml` from `root`, falling back to `contract_path`'s
+    /// parent directory when no explicit root is given.
+    pub fn load(contract_path: &Path, root: Option<&PathBuf>) -> Result<Self> {
+        let manifest_dir = match root {
+            Some(root) => root.clone(),
+            None => contract_path
+                .parent()
+                .map(Path::to_path_buf)
+                .unwrap_or_else(|| PathBuf::from(".")),
+        };
+        let manifest_path = manifest_dir.join("SwiftSC-Lang.toml");
+        let content = std::fs::read_to_string(&manifest_path)
+            .with_context(|| format!("could not read manifest `{}`", manifest_path.display()))?;
+        toml::from_str(&content)
+            .with_context(|| format!("could not parse manifest `{}`", manifest_path.display()))
+    }
+}
@@ -0,0 +1,130 @@
+use swiftsc_frontend::Span;
+
+/// Severity label shown in the gutter, following the usual
+/// error/warning two-tier scheme.
+#[derive(Clone, Copy)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+impl Severity {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        }
+    }
+}
+
+/// Finds the 1-indexed line number and the (start, end) byte offsets of the
+/// line containing `offset` within `source`.
+fn line_containing(source: &str, offset: usize) -> (usize, usize, usize) {
+    let offset = offset.min(source.len());
+    let mut line_no = 1;
+    let mut line_start = 0;
+    for (i, b) in source.as_bytes().iter().enumerate() {
+        if i >= offset {
+            break;
+        }
+        if *b == b'\n' {
+            line_no += 1;
+            line_start = i + 1;
+        }
+    }
+    let line_end = source[line_start..]
+        .find('\n')
+        .map(|i| line_start + i)
+        .unwrap_or(source.len());
+    (line_no, line_start, line_end)
+}
+
+/// Length of the caret underline for a span starting within `[line_start,
+/// line_end)`. Spans that run past the end of the line (an unterminated
+/// string or an unclosed brace typically spans to EOF or the next token)
+/// are clipped to the line, so the underline never runs past the source
+/// excerpt it sits under.
+fn underline_len(span: Span, line_end: usize) -> usize {
+    span.end.min(line_end).saturating_sub(span.start).max(1)
+}
+
+/// Renders `message` with a source excerpt and caret underline pointing at
+/// `span`, in the style of `annotate-snippets`:
+///
+/// ```text
+/// error: unexpected token `;`
+///   --> contract.stc:4:9
+///   |
+/// 4 | let x = ;
+///   |         ^
+/// ```
+pub fn render(source_name: &str, source: &str, span: Span, severity: Severity, message: &str) {
+    let (line_no, line_start, line_end) = line_containing(source, span.start);
+    let line_text = &source[line_start..line_end];
+
+    let col = span.start.saturating_sub(line_start);
+    let underline_len = underline_len(span, line_end);
+
+    let gutter = format!("{line_no}");
+    let pad = " ".repeat(gutter.len());
+
+    eprintln!("{}: {}", severity.as_str(), message);
+    eprintln!("{pad} --> {source_name}:{line_no}:{}", col + 1);
+    eprintln!("{pad} |");
+    eprintln!("{gutter} | {line_text}");
+    eprintln!(
+        "{pad} | {}{}",
+        " ".repeat(col),
+        "^".repeat(underline_len)
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{line_containing, underline_len};
+    use swiftsc_frontend::Span;
+
+    #[test]
+    fn first_line() {
+        let source = "let x = 1;\nlet y = 2;\n";
+        assert_eq!(line_containing(source, 4), (1, 0, 10));
+    }
+
+    #[test]
+    fn later_line() {
+        let source = "let x = 1;\nlet y = 2;\n";
+        assert_eq!(line_containing(source, 15), (2, 11, 21));
+    }
+
+    #[test]
+    fn offset_at_newline_belongs_to_preceding_line() {
+        let source = "abc\ndef";
+        assert_eq!(line_containing(source, 3), (1, 0, 3));
+    }
+
+    #[test]
+    fn offset_past_end_clamps_to_last_line() {
+        let source = "abc\ndef";
+        assert_eq!(line_containing(source, 100), (2, 4, 7));
+    }
+
+    #[test]
+    fn underline_fits_within_a_single_line_span() {
+        let span = Span { start: 8, end: 10 };
+        assert_eq!(underline_len(span, 10), 2);
+    }
+
+    #[test]
+    fn underline_clips_a_span_that_runs_past_the_line() {
+        // An unterminated string typically spans from its opening quote to
+        // EOF, well past the line it starts on.
+        let span = Span { start: 8, end: 40 };
+        assert_eq!(underline_len(span, 10), 2);
+    }
+
+    #[test]
+    fn underline_is_never_zero_width() {
+        let span = Span { start: 5, end: 5 };
+        assert_eq!(underline_len(span, 10), 1);
+    }
+}
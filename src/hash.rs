@@ -0,0 +1,136 @@
+use anyhow::{Context, Result};
+use sha3::{Digest, Keccak256};
+
+/// Strips non-deterministic sections (the custom `name` section and any
+/// `producers`/debug custom sections) from a WASM module so that two builds
+/// of identical source hash identically regardless of build-time metadata.
+fn canonicalize(wasm_bytes: &[u8]) -> Result<Vec<u8>> {
+    let mut module = wasm_encoder::Module::new();
+
+    for payload in wasmparser::Parser::new(0).parse_all(wasm_bytes) {
+        let payload = payload.context("malformed WASM module")?;
+        match payload {
+            wasmparser::Payload::CustomSection(_) => {
+                // Drop `name`, `producers`, and any other debug/custom metadata.
+            }
+            wasmparser::Payload::End(_) => {}
+            other => {
+                if let Some((id, range)) = other.as_section() {
+                    module.section(&wasm_encoder::RawSection {
+                        id,
+                        data: &wasm_bytes[range],
+                    });
+                }
+            }
+        }
+    }
+
+    Ok(module.finish())
+}
+
+/// Computes the keccak-256 digest of the canonicalized code, used as the
+/// reproducible-build fingerprint for a compiled contract. `Deploy` and
+/// `Verify` must use this same fingerprint, or a deployed contract's printed
+/// hash can never match what a later `verify` rebuild computes.
+pub fn code_hash(wasm_bytes: &[u8]) -> Result<String> {
+    let canonical = canonicalize(wasm_bytes)?;
+    let mut hasher = Keccak256::new();
+    hasher.update(&canonical);
+    Ok(hex::encode(hasher.finalize()))
+}
+
+/// Gives the human name for a top-level WASM section id, falling back to the
+/// raw id for the handful of sections (tag, datacount, ...) nobody names in
+/// mismatch diagnostics.
+fn section_name(id: u8) -> String {
+    match id {
+        1 => "type".to_string(),
+        2 => "import".to_string(),
+        3 => "function".to_string(),
+        4 => "table".to_string(),
+        5 => "memory".to_string(),
+        6 => "global".to_string(),
+        7 => "export".to_string(),
+        8 => "start".to_string(),
+        9 => "element".to_string(),
+        10 => "code".to_string(),
+        11 => "data".to_string(),
+        other => format!("id {other}"),
+    }
+}
+
+/// Splits a canonicalized module into its `(section id, section bytes)`
+/// pairs, in on-disk order.
+fn canonical_sections(wasm_bytes: &[u8]) -> Result<Vec<(u8, Vec<u8>)>> {
+    let canonical = canonicalize(wasm_bytes)?;
+    let mut sections = Vec::new();
+    for payload in wasmparser::Parser::new(0).parse_all(&canonical) {
+        let payload = payload.context("malformed WASM module")?;
+        if let Some((id, range)) = payload.as_section() {
+            sections.push((id, canonical[range].to_vec()));
+        }
+    }
+    Ok(sections)
+}
+
+/// Compares the canonicalized sections of two WASM modules and names the
+/// first one that differs, for `Verify`'s mismatch diagnostics. Returns
+/// `None` if every section lines up (a hash mismatch should not be possible
+/// in that case, but callers should not assume it can't happen).
+pub fn first_differing_section(rebuilt: &[u8], supplied: &[u8]) -> Result<Option<String>> {
+    let rebuilt_sections = canonical_sections(rebuilt)?;
+    let supplied_sections = canonical_sections(supplied)?;
+
+    for (i, (a, b)) in rebuilt_sections.iter().zip(supplied_sections.iter()).enumerate() {
+        if a != b {
+            let (id, _) = a;
+            return Ok(Some(format!("{} section (index {i})", section_name(*id))));
+        }
+    }
+
+    if rebuilt_sections.len() != supplied_sections.len() {
+        return Ok(Some(format!(
+            "section count ({} vs {})",
+            rebuilt_sections.len(),
+            supplied_sections.len()
+        )));
+    }
+
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::canonicalize;
+
+    /// Builds a minimal valid module (an empty type section) and tags it
+    /// with a custom section named `producer_name`, mimicking the debug
+    /// metadata a real toolchain stamps into its output.
+    fn module_with_custom_section(producer_name: &str, custom_data: &[u8]) -> Vec<u8> {
+        let mut module = wasm_encoder::Module::new();
+        module.section(&wasm_encoder::TypeSection::new());
+        module.section(&wasm_encoder::CustomSection {
+            name: producer_name.into(),
+            data: custom_data.into(),
+        });
+        module.finish()
+    }
+
+    #[test]
+    fn drops_custom_sections() {
+        let wasm = module_with_custom_section("producers", b"swiftsc 0.1.0");
+        let canonical = canonicalize(&wasm).unwrap();
+
+        assert!(wasmparser::Parser::new(0)
+            .parse_all(&canonical)
+            .all(|payload| !matches!(payload.unwrap(), wasmparser::Payload::CustomSection(_))));
+    }
+
+    #[test]
+    fn identical_code_with_different_metadata_canonicalizes_identically() {
+        let a = module_with_custom_section("producers", b"swiftsc 0.1.0");
+        let b = module_with_custom_section("name", b"built on a different machine");
+
+        assert_eq!(canonicalize(&a).unwrap(), canonicalize(&b).unwrap());
+    }
+}
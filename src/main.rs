@@ -1,11 +1,24 @@
 use anyhow::{Context, Result};
 use clap::{Parser, Subcommand};
 use std::path::PathBuf;
+use std::process::ExitCode;
 use swiftsc_frontend::{parse, tokenize};
 
+mod commands;
+mod config;
+mod diagnostics;
+mod hash;
+mod output;
+
+use diagnostics::Severity;
+use output::{Format, JsonDiagnostic, JsonToken};
+
 #[derive(Parser)]
 #[command(author, version, about, long_about = None)]
 struct Cli {
+    /// Output mode: human-readable text, or structured JSON for tooling
+    #[arg(long, global = true, value_enum, default_value = "human")]
+    format: Format,
     #[command(subcommand)]
     command: Commands,
 }
@@ -47,6 +60,25 @@ enum Commands {
         network: String,
         #[arg(short, long)]
         root: Option<PathBuf>,
+        /// Build and hash the contract without broadcasting a transaction
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Verify that a built WASM blob matches a reproducible build of the source
+    Verify {
+        path: PathBuf,
+        #[arg(short, long)]
+        wasm: PathBuf,
+        #[arg(short, long)]
+        root: Option<PathBuf>,
+    },
+    /// Generate a JSON schema describing the contract's public interface
+    Schema {
+        path: PathBuf,
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+        #[arg(short, long)]
+        root: Option<PathBuf>,
     },
     /// Run security analysis
     Analyze {
@@ -56,7 +88,7 @@ enum Commands {
     },
 }
 
-fn main() -> Result<()> {
+fn main() -> Result<ExitCode> {
     let cli = Cli::parse();
 
     match &cli.command {
@@ -64,11 +96,22 @@ fn main() -> Result<()> {
             let content = std::fs::read_to_string(path)
                 .with_context(|| format!("could not read file `{}`", path.display()))?;
 
-            println!("--- Lexing: {} ---", path.display());
             let tokens = tokenize(&content);
 
-            for (token, span) in tokens {
-                println!("{:?} => {:?}", span, token);
+            if cli.format.is_json() {
+                let json_tokens: Vec<JsonToken> = tokens
+                    .into_iter()
+                    .map(|(token, span)| JsonToken {
+                        span: span.into(),
+                        token: format!("{token:?}"),
+                    })
+                    .collect();
+                output::emit_json(&json_tokens);
+            } else {
+                println!("--- Lexing: {} ---", path.display());
+                for (token, span) in tokens {
+                    println!("{:?} => {:?}", span, token);
+                }
             }
         }
         Commands::Parse { path } => {
@@ -78,43 +121,126 @@ fn main() -> Result<()> {
             println!("--- Parsing: {} ---", path.display());
             match parse(&content) {
                 Ok(ast) => println!("{:#?}", ast),
-                Err(e) => eprintln!("Error: {}", e),
+                Err(e) => {
+                    let name = path.display().to_string();
+                    diagnostics::render(&name, &content, e.span(), Severity::Error, &e.to_string());
+                    return Ok(ExitCode::FAILURE);
+                }
             }
         }
         Commands::Check { path, root } => {
             let content = std::fs::read_to_string(path)
                 .with_context(|| format!("could not read file `{}`", path.display()))?;
+            let name = path.display().to_string();
 
-            match parse(&content) {
+            let diagnostic = match parse(&content) {
                 Ok(ast) => match swiftsc_frontend::analyze(&ast, root.clone()) {
-                    Ok(_) => println!("Semantic Check Passed"),
-                    Err(e) => eprintln!("Semantic Error: {}", e),
+                    Ok(_) => None,
+                    Err(e) => Some(JsonDiagnostic::new(
+                        Severity::Error,
+                        e.span(),
+                        format!("Semantic Error: {e}"),
+                    )),
                 },
-                Err(e) => eprintln!("Parse Error: {}", e),
+                Err(e) => Some(JsonDiagnostic::new(
+                    Severity::Error,
+                    e.span(),
+                    format!("Parse Error: {e}"),
+                )),
+            };
+
+            let has_diagnostic = diagnostic.is_some();
+
+            if cli.format.is_json() {
+                output::emit_json(&diagnostic.into_iter().collect::<Vec<_>>());
+            } else {
+                match diagnostic {
+                    None => println!("Semantic Check Passed"),
+                    Some(d) => diagnostics::render(&name, &content, d.span_value(), Severity::Error, &d.message),
+                }
+            }
+
+            if has_diagnostic {
+                return Ok(ExitCode::FAILURE);
             }
         }
         Commands::Build { path, output, root } => {
+            let config = config::ProjectConfig::load(path, root.as_ref())
+                .context("could not load SwiftSC-Lang.toml for this contract")?;
+
             let content = std::fs::read_to_string(path)
                 .with_context(|| format!("could not read file `{}`", path.display()))?;
 
-            println!("--- Compiling: {} ---", path.display());
-            match parse(&content) {
+            if !cli.format.is_json() {
+                println!("--- Compiling: {} ---", path.display());
+            }
+
+            let options = swiftsc_backend::CompileOptions {
+                target: config.build.target.clone(),
+                gas_metering: config.build.gas_metering,
+            };
+
+            let report = match parse(&content) {
                 Ok(ast) => match swiftsc_frontend::analyze(&ast, root.clone()) {
-                    Ok(_) => match swiftsc_backend::compile(&ast) {
-                        Ok(wasm_bytes) => {
-                            let output_path = output
-                                .clone()
-                                .unwrap_or_else(|| path.with_extension("wasm"));
-                            std::fs::write(&output_path, wasm_bytes).with_context(|| {
-                                format!("could not write output file `{}`", output_path.display())
-                            })?;
-                            println!("Build Successful: {}", output_path.display());
-                        }
-                        Err(e) => eprintln!("Codegen Error: {}", e),
+                    Ok(_) => match swiftsc_backend::compile_with_options(&ast, &options) {
+                        Ok(wasm_bytes) => match hash::code_hash(&wasm_bytes) {
+                            Ok(code_hash) => {
+                                let output_path = output
+                                    .clone()
+                                    .unwrap_or_else(|| path.with_extension("wasm"));
+                                std::fs::write(&output_path, wasm_bytes).with_context(|| {
+                                    format!(
+                                        "could not write output file `{}`",
+                                        output_path.display()
+                                    )
+                                })?;
+                                output::BuildReport {
+                                    success: true,
+                                    output: Some(output_path.display().to_string()),
+                                    code_hash: Some(code_hash),
+                                    error: None,
+                                }
+                            }
+                            Err(e) => output::BuildReport {
+                                success: false,
+                                output: None,
+                                code_hash: None,
+                                error: Some(format!("Codegen Error: {e}")),
+                            },
+                        },
+                        Err(e) => output::BuildReport {
+                            success: false,
+                            output: None,
+                            code_hash: None,
+                            error: Some(format!("Codegen Error: {e}")),
+                        },
+                    },
+                    Err(e) => output::BuildReport {
+                        success: false,
+                        output: None,
+                        code_hash: None,
+                        error: Some(format!("Semantic Error: {e}")),
                     },
-                    Err(e) => eprintln!("Semantic Error: {}", e),
                 },
-                Err(e) => eprintln!("Parse Error: {}", e),
+                Err(e) => output::BuildReport {
+                    success: false,
+                    output: None,
+                    code_hash: None,
+                    error: Some(format!("Parse Error: {e}")),
+                },
+            };
+
+            if cli.format.is_json() {
+                output::emit_json(&report);
+            } else if report.success {
+                println!("Build Successful: {}", report.output.as_deref().unwrap_or_default());
+                println!("  code hash: 0x{}", report.code_hash.as_deref().unwrap_or_default());
+            } else {
+                eprintln!("{}", report.error.as_deref().unwrap_or("Build failed"));
+            }
+
+            if !report.success {
+                return Ok(ExitCode::FAILURE);
             }
         }
         Commands::Init { path } => {
@@ -170,43 +296,79 @@ contract MyContract {
             println!("  - tests/");
         }
         Commands::Test { path } => {
-            println!("--- Running tests in: {} ---", path.display());
-
-            // Find all test files
-            let test_dir = path.join("tests");
-            if test_dir.exists() {
-                println!("✓ Test directory found");
-                println!("  (Test execution not yet implemented)");
-            } else {
-                eprintln!("✗ No tests directory found");
+            let all_passed = commands::test::run(path, cli.format)?;
+            if !all_passed {
+                return Ok(ExitCode::FAILURE);
+            }
+        }
+        Commands::Deploy {
+            path,
+            network,
+            root,
+            dry_run,
+        } => {
+            let succeeded = commands::deploy::run(path, network, root.clone(), *dry_run, cli.format)?;
+            if !succeeded {
+                return Ok(ExitCode::FAILURE);
+            }
+        }
+        Commands::Verify { path, wasm, root } => {
+            let matched = commands::verify::run(path, wasm, root.clone(), cli.format)?;
+            if !matched {
+                return Ok(ExitCode::FAILURE);
+            }
+        }
+        Commands::Schema { path, output, root } => {
+            let succeeded = commands::schema::run(path, output.clone(), root.clone(), cli.format)?;
+            if !succeeded {
+                return Ok(ExitCode::FAILURE);
             }
         }
         Commands::Analyze { path, verbose } => {
             let content = std::fs::read_to_string(path)
                 .with_context(|| format!("could not read file `{}`", path.display()))?;
+            let name = path.display().to_string();
+            let json_mode = cli.format.is_json();
 
             match parse(&content) {
                 Ok(ast) => {
-                    if *verbose {
+                    if *verbose && !json_mode {
                         println!("--- Analyzing AST: {} ---", path.display());
                         println!("Pass 1: Reentrancy Detection");
                         println!("Pass 2: Integer Overflow Check");
                         println!("Pass 3: Uninitialized Storage Check");
                     }
                     let warnings = swiftsc_analyzer::SecurityAnalyzer::analyze(&ast);
-                    if warnings.is_empty() {
+                    let diagnostics_list: Vec<JsonDiagnostic> = warnings
+                        .iter()
+                        .map(|w| JsonDiagnostic::new(Severity::Warning, w.span(), format!("{:?}", w)))
+                        .collect();
+
+                    if json_mode {
+                        output::emit_json(&diagnostics_list);
+                    } else if warnings.is_empty() {
                         println!("✓ No security issues found.");
                     } else {
                         println!("⚠️ Found {} security warnings:", warnings.len());
-                        for warning in warnings {
-                            println!("  - {:?}", warning);
+                        for d in &diagnostics_list {
+                            diagnostics::render(&name, &content, d.span_value(), Severity::Warning, &d.message);
                         }
                     }
+                    // Warnings alone don't fail the command (same as `cargo
+                    // check` without `-D warnings`); only a parse error does.
+                }
+                Err(e) => {
+                    let d = JsonDiagnostic::new(Severity::Error, e.span(), e.to_string());
+                    if json_mode {
+                        output::emit_json(&vec![d]);
+                    } else {
+                        diagnostics::render(&name, &content, d.span_value(), Severity::Error, &d.message);
+                    }
+                    return Ok(ExitCode::FAILURE);
                 }
-                Err(e) => eprintln!("✗ Parse error: {}", e),
             }
         }
     }
 
-    Ok(())
+    Ok(ExitCode::SUCCESS)
 }
@@ -0,0 +1,135 @@
+use clap::ValueEnum;
+use serde::Serialize;
+use swiftsc_frontend::Span;
+
+use crate::diagnostics::Severity;
+
+/// Output mode shared by every subcommand, mirroring `cargo`'s
+/// `--message-format=json`: `human` is the default, readable format;
+/// `json` emits machine-parseable records for editors and CI.
+#[derive(Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    #[default]
+    Human,
+    Json,
+}
+
+impl Format {
+    pub fn is_json(self) -> bool {
+        matches!(self, Format::Json)
+    }
+}
+
+#[derive(Serialize)]
+pub struct JsonSpan {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl From<Span> for JsonSpan {
+    fn from(span: Span) -> Self {
+        Self {
+            start: span.start,
+            end: span.end,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct JsonToken {
+    pub span: JsonSpan,
+    pub token: String,
+}
+
+#[derive(Serialize)]
+pub struct JsonDiagnostic {
+    pub severity: &'static str,
+    pub message: String,
+    pub span: JsonSpan,
+    // No diagnostic produced by `swiftsc_frontend`/`swiftsc_analyzer` carries
+    // an error code today, so this is always `null`. Kept (rather than
+    // dropped) because the field is part of the documented wire shape; wire
+    // a real code through from those crates' error types once one exists
+    // instead of quietly removing it.
+    pub code: Option<String>,
+    #[serde(skip)]
+    source_span: Span,
+}
+
+impl JsonDiagnostic {
+    pub fn new(severity: Severity, span: Span, message: impl Into<String>) -> Self {
+        Self {
+            severity: severity.as_str(),
+            message: message.into(),
+            span: span.into(),
+            code: None,
+            source_span: span,
+        }
+    }
+
+    /// The original [`Span`], for use by the human-readable renderer.
+    pub fn span_value(&self) -> Span {
+        self.source_span
+    }
+}
+
+#[derive(Serialize)]
+pub struct BuildReport {
+    pub success: bool,
+    pub output: Option<String>,
+    pub code_hash: Option<String>,
+    pub error: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct VerifyReport {
+    pub success: bool,
+    pub path: String,
+    pub wasm: String,
+    pub rebuilt_hash: Option<String>,
+    pub supplied_hash: Option<String>,
+    pub error: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct DeployReport {
+    pub success: bool,
+    pub dry_run: bool,
+    pub network: String,
+    pub address: Option<String>,
+    pub code_hash: Option<String>,
+    pub error: Option<String>,
+}
+
+/// One `test_*` export's outcome, reported alongside the `*.stc` file it
+/// came from (a `swiftsc test` run spans every test file under `tests/`, so
+/// a bare test name alone wouldn't disambiguate which file it failed in).
+#[derive(Serialize)]
+pub struct JsonTestResult {
+    pub file: String,
+    pub name: String,
+    pub passed: bool,
+    pub message: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct TestReport {
+    pub success: bool,
+    pub passed: usize,
+    pub failed: usize,
+    pub results: Vec<JsonTestResult>,
+    /// `*.stc` files under `tests/` that compiled but exported no `test_*`
+    /// function, so they contributed nothing to `passed`/`failed`. Kept
+    /// separate from `results` rather than folded in with a made-up
+    /// pass/fail verdict, so `results.len() == passed + failed` always holds.
+    pub empty_files: Vec<String>,
+    pub error: Option<String>,
+}
+
+/// Serializes `value` as a single line of JSON on stdout.
+pub fn emit_json<T: Serialize>(value: &T) {
+    println!(
+        "{}",
+        serde_json::to_string(value).expect("output types are always serializable")
+    );
+}